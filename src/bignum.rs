@@ -0,0 +1,165 @@
+#![cfg(feature = "bignum")]
+
+use core::ops::{Add, Mul};
+
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, ToPrimitive};
+
+use crate::mandelbrot::Escapable;
+
+/// A fixed-point complex number for zooms past `f64`'s ~15 significant
+/// digits of precision.
+///
+/// Each component is an arbitrary-precision integer `v` denoting `v /
+/// 2^k` for a caller-chosen fractional precision `k`. Addition is a
+/// direct integer add; multiplication multiplies the `BigInt`s and
+/// rescales by shifting right `k` bits. [`Viewport::precision_bits`]
+/// picks `k` from the current zoom level.
+///
+/// [`Viewport::precision_bits`]: crate::mandelbrot::Viewport::precision_bits
+#[derive(Clone)]
+pub struct FixedComplex {
+    real: BigInt,
+    imaginary: BigInt,
+    k: u32,
+}
+
+impl FixedComplex {
+    pub fn new(real: BigInt, imaginary: BigInt, k: u32) -> Self {
+        Self { real, imaginary, k }
+    }
+
+    /// Encodes an `(f64, f64)` pair at fractional precision `k`.
+    ///
+    /// Note this is only as precise as the `f64` inputs themselves —
+    /// resolving a deep zoom end-to-end also requires the viewport
+    /// center to be tracked as a `FixedComplex`, not just the per-pixel
+    /// offset computed from it.
+    ///
+    /// Scales via [`BigInt::from_f64`] rather than an `i64` cast — at
+    /// `k` of 64 or more (see [`Viewport::precision_bits`]), `real *
+    /// 2^k` routinely exceeds `i64::MAX` even for small `real`.
+    ///
+    /// [`Viewport::precision_bits`]: crate::mandelbrot::Viewport::precision_bits
+    pub fn from_f64(real: f64, imaginary: f64, k: u32) -> Self {
+        let scale = 2f64.powi(k as i32);
+        Self {
+            real: BigInt::from_f64((real * scale).round()).expect("finite input"),
+            imaginary: BigInt::from_f64((imaginary * scale).round()).expect("finite input"),
+            k,
+        }
+    }
+
+    fn norm_sqr(&self) -> BigInt {
+        &self.real * &self.real + &self.imaginary * &self.imaginary
+    }
+
+    /// `4 · 2^(2k)`: the radius-2 escape test, scaled to this fixed-point
+    /// representation.
+    fn escape_threshold(&self) -> BigInt {
+        BigInt::from(4) << (2 * self.k)
+    }
+}
+
+impl Add for FixedComplex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            real: self.real + rhs.real,
+            imaginary: self.imaginary + rhs.imaginary,
+            k: self.k,
+        }
+    }
+}
+
+impl Mul for FixedComplex {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let k = self.k;
+        let real = (&self.real * &rhs.real) - (&self.imaginary * &rhs.imaginary);
+        let imaginary = (&self.real * &rhs.imaginary) + (&self.imaginary * &rhs.real);
+
+        Self {
+            real: real >> k,
+            imaginary: imaginary >> k,
+            k,
+        }
+    }
+}
+
+impl Escapable for FixedComplex {
+    fn zero_like(&self) -> Self {
+        Self {
+            real: BigInt::from(0),
+            imaginary: BigInt::from(0),
+            k: self.k,
+        }
+    }
+
+    fn has_escaped(&self) -> bool {
+        self.norm_sqr() > self.escape_threshold()
+    }
+
+    fn approx_abs(&self) -> f64 {
+        let scale = 2f64.powi(2 * self.k as i32);
+        let normalized = self.norm_sqr().to_f64().unwrap_or(f64::INFINITY) / scale;
+        normalized.sqrt()
+    }
+}
+
+#[test]
+fn add() {
+    let a = FixedComplex::from_f64(1.5, 2.25, 8);
+    let b = FixedComplex::from_f64(0.5, -0.25, 8);
+    let sum = a + b;
+    assert_eq!(sum.real, BigInt::from(512));
+    assert_eq!(sum.imaginary, BigInt::from(512));
+}
+
+#[test]
+fn mul() {
+    let a = FixedComplex::from_f64(2.0, 3.0, 8);
+    let b = FixedComplex::from_f64(1.0, -1.0, 8);
+    let product = a * b;
+    assert_eq!(product.real, BigInt::from(5 * 256));
+    assert_eq!(product.imaginary, BigInt::from(256));
+}
+
+#[test]
+fn has_escaped() {
+    let inside = FixedComplex::from_f64(0.5, 0.5, 16);
+    assert!(!inside.has_escaped());
+    let outside = FixedComplex::from_f64(3.0, 3.0, 16);
+    assert!(outside.has_escaped());
+}
+
+#[test]
+fn zero_like_matches_precision() {
+    let a = FixedComplex::from_f64(1.0, 1.0, 10);
+    let zero = a.zero_like();
+    assert_eq!(zero.real, BigInt::from(0));
+    assert_eq!(zero.k, 10);
+}
+
+/// Drives [`Viewport::precision_bits`](crate::mandelbrot::Viewport::precision_bits)
+/// into `FixedComplex` and checks the deep-zoom backend agrees with `C64`
+/// on a point that still fits in `f64`.
+#[test]
+fn escape_matches_f64_backend_at_viewport_precision() {
+    use crate::complex::C64;
+    use crate::mandelbrot::{escape, Viewport};
+
+    let viewport = Viewport::new(C64::from((-0.5, 0.0)), 1e-9, 80, 40);
+    let k = viewport.precision_bits();
+
+    let (re, im) = (2.0, 2.0);
+    let c_f64: C64 = (re, im).into();
+    let c_fixed = FixedComplex::from_f64(re, im, k);
+
+    let nu_f64 = escape(c_f64, 50).expect("escapes quickly");
+    let nu_fixed = escape(c_fixed, 50).expect("escapes quickly");
+
+    assert_eq!(nu_f64.round(), nu_fixed.round());
+}
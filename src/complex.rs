@@ -1,6 +1,7 @@
-use std::convert::From;
-use std::fmt::{self, Debug, Display};
-use std::ops::{Add, Mul, Sub};
+use core::fmt::{self, Debug, Display};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use num_traits::{Float, Num};
 
 pub const ORIGIN: Complex<f32> = Complex { real: 0.0, imaginary: 0.0 };
 
@@ -14,6 +15,14 @@ pub type C64 = Complex<f64>;
 /// Only the operations needed for recreating the mandelbrot
 /// set are implemented, which essentially boils down to
 /// addition, subtraction, multiplication, and (integer) exponentation.
+///
+/// Numeric operations are bounded by `num-traits` so the same code
+/// works for any `Num`/`Float` impl. With the `libm` feature enabled
+/// (and `std` disabled in `num-traits`), the `Float`-bounded methods
+/// below (`sqrt`, `powi`, `abs`) resolve through `libm` instead of
+/// `std`. Note this only covers `Complex<T>` itself — `view`, `palette`
+/// and `image` still depend on `std` for `String`/`Vec` and aren't
+/// built for `no_std` targets.
 #[derive(PartialEq, Eq, Clone, Copy)]
 pub struct Complex<T> {
     real: T,
@@ -42,33 +51,71 @@ impl<T: Sub<Output = T>> Sub for Complex<T> {
     }
 }
 
-impl<T: Mul<Output = T>> Mul for Complex<T> {
+impl<T: Copy + Num> Mul for Complex<T> {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
         Self {
-            real: self.real * rhs.real,
-            imaginary: self.imaginary * rhs.imaginary,
+            real: self.real * rhs.real - self.imaginary * rhs.imaginary,
+            imaginary: self.real * rhs.imaginary + self.imaginary * rhs.real,
+        }
+    }
+}
+
+impl<T: Copy + Num> Div for Complex<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let denom = rhs.real * rhs.real + rhs.imaginary * rhs.imaginary;
+
+        Self {
+            real: (self.real * rhs.real + self.imaginary * rhs.imaginary) / denom,
+            imaginary: (self.imaginary * rhs.real - self.real * rhs.imaginary) / denom,
         }
     }
 }
 
-impl<T: Copy + Mul<Output = T>> Complex<T> {
+impl<T: Copy + Num> Complex<T> {
+    /// Squared norm (`re² + im²`), cheaper than the magnitude (e.g.
+    /// [`Complex::distance`] from the origin) since it avoids a `sqrt`.
+    pub fn norm_sqr(self) -> T {
+        self.real * self.real + self.imaginary * self.imaginary
+    }
+
+    /// Raises this number to the `n`th (integer) power via repeated complex
+    /// multiplication.
+    ///
+    /// `powi(0)` is the multiplicative identity `1 + 0i`. Negative `n` is
+    /// computed as `powi(-n)` of the reciprocal `1 / self`.
     pub fn powi(self, n: i32) -> Self {
-        let mut real = self.real;
-        let mut imaginary = self.imaginary;
+        let one = Self {
+            real: T::one(),
+            imaginary: T::zero(),
+        };
 
+        if n < 0 {
+            return (one / self).powi(-n);
+        }
+
+        let mut result = one;
         for _ in 0..n {
-            real = real * real;
-            imaginary = imaginary * imaginary;
+            result = result * self;
         }
+        result
+    }
+}
 
-        Self { real, imaginary }
+impl<T: Copy + Num + Neg<Output = T>> Complex<T> {
+    /// The complex conjugate, `a - bi`.
+    pub fn conj(self) -> Self {
+        Self {
+            real: self.real,
+            imaginary: -self.imaginary,
+        }
     }
 }
 
-// @TODO: consolidate f32/f64 specific impls into macro
-impl Complex<f32> {
+impl<T: Float> Complex<T> {
     pub fn abs(self) -> Self {
         Self {
             real: self.real.abs(),
@@ -76,7 +123,7 @@ impl Complex<f32> {
         }
     }
 
-    pub fn distance(self, other: Complex<f32>) -> f32 {
+    pub fn distance(self, other: Complex<T>) -> T {
         let p = (self - other).abs();
         (p.real.powi(2) + p.imaginary.powi(2)).sqrt()
     }
@@ -144,17 +191,39 @@ fn subtraction() {
 fn multiplication() {
     let a: Complex<f32> = (2.0, 5.0).into();
     let b: Complex<f32> = (7.0, 2.0).into();
-    assert_eq!(a * b, (14.0, 10.0).into())
+    assert_eq!(a * b, (4.0, 39.0).into())
+}
+
+#[test]
+fn division() {
+    let a: Complex<f32> = (2.0, 5.0).into();
+    let b: Complex<f32> = (7.0, 2.0).into();
+    assert_eq!(a / b, (0.4528302, 0.5849057).into())
+}
+
+#[test]
+fn norm_sqr() {
+    let a: Complex<f32> = (2.0, 5.0).into();
+    assert_eq!(a.norm_sqr(), 29.0)
+}
+
+#[test]
+fn conj() {
+    let a: Complex<f32> = (2.0, 5.0).into();
+    assert_eq!(a.conj(), (2.0, -5.0).into())
 }
 
 #[test]
 fn powi() {
     let a: Complex<f32> = (2.0, 5.0).into();
 
-    assert_eq!(a.powi(0), a);
-    assert_eq!(a.powi(1), (4.0, 25.0).into());
-    assert_eq!(a.powi(2), (16.0, 625.0).into());
-    assert_eq!(a.powi(3), (256.0, 390625.0).into());
+    assert_eq!(a.powi(0), (1.0, 0.0).into());
+    assert_eq!(a.powi(1), (2.0, 5.0).into());
+    assert_eq!(a.powi(2), (-21.0, 20.0).into());
+    assert_eq!(a.powi(3), (-142.0, -65.0).into());
+
+    let one: Complex<f32> = (1.0, 0.0).into();
+    assert_eq!(a.powi(-1), one / a);
 }
 
 #[test]
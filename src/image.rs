@@ -0,0 +1,135 @@
+use crate::palette::ColorPalette;
+use crate::view::View;
+
+/// Which terminal inline-image protocol to wrap a rendered raster in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ImageProtocol {
+    /// iTerm2's `OSC 1337 File=` inline image protocol.
+    Iterm2,
+    /// The kitty terminal graphics protocol (raw RGB, chunked at 4096
+    /// base64 bytes per escape).
+    Kitty,
+}
+
+/// Kitty requires each `a=T` escape's base64 payload to be chunked at
+/// this many bytes.
+const KITTY_CHUNK_SIZE: usize = 4096;
+
+impl<T: Copy> View<T> {
+    /// Renders this view as a pixel-accurate raster image (instead of
+    /// down-sampling it to glyphs) and wraps it in the given terminal's
+    /// inline-image escape sequence.
+    pub fn to_inline_image(&self, palette: &impl ColorPalette<T>, protocol: ImageProtocol) -> String {
+        let mut rgb = Vec::with_capacity(self.width as usize * self.height as usize * 3);
+        self.iter().for_each(|(_, _, val)| {
+            let (r, g, b) = palette.color(&val);
+            rgb.extend_from_slice(&[r, g, b]);
+        });
+
+        match protocol {
+            ImageProtocol::Iterm2 => iterm2_escape(&ppm(self.width, self.height, &rgb)),
+            ImageProtocol::Kitty => kitty_escape(self.width, self.height, &rgb),
+        }
+    }
+}
+
+/// Wraps a raw RGB buffer in a minimal P6 PPM header — trivial to pack
+/// and, unlike raw pixels, self-describing enough for iTerm2 to decode.
+fn ppm(width: u16, height: u16, rgb: &[u8]) -> Vec<u8> {
+    let mut out = format!("P6\n{width} {height}\n255\n").into_bytes();
+    out.extend_from_slice(rgb);
+    out
+}
+
+fn iterm2_escape(image: &[u8]) -> String {
+    let encoded = base64_encode(image);
+    format!("\x1b]1337;File=inline=1;size={}:{encoded}\x07", image.len())
+}
+
+fn kitty_escape(width: u16, height: u16, rgb: &[u8]) -> String {
+    let encoded = base64_encode(rgb);
+    let chunks: Vec<&[u8]> = encoded.as_bytes().chunks(KITTY_CHUNK_SIZE).collect();
+    let mut out = String::new();
+
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = if i == chunks.len() - 1 { 0 } else { 1 };
+        let chunk = std::str::from_utf8(chunk).expect("base64 output is always ASCII");
+
+        if i == 0 {
+            out.push_str(&format!(
+                "\x1b_Ga=T,f=24,s={width},v={height},m={more};{chunk}\x1b\\"
+            ));
+        } else {
+            out.push_str(&format!("\x1b_Gm={more};{chunk}\x1b\\"));
+        }
+    }
+
+    out
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// A small dependency-free base64 encoder (standard alphabet, `=`-padded).
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+
+        out.push(BASE64_ALPHABET[(n >> 18 & 0x3f) as usize] as char);
+        out.push(BASE64_ALPHABET[(n >> 12 & 0x3f) as usize] as char);
+        out.push(if chunk.len() > 1 {
+            BASE64_ALPHABET[(n >> 6 & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            BASE64_ALPHABET[(n & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+    }
+
+    out
+}
+
+#[test]
+fn base64_encode_known_vectors() {
+    assert_eq!(base64_encode(b""), "");
+    assert_eq!(base64_encode(b"M"), "TQ==");
+    assert_eq!(base64_encode(b"Ma"), "TWE=");
+    assert_eq!(base64_encode(b"Man"), "TWFu");
+}
+
+#[test]
+fn ppm_header_and_payload() {
+    let rgb = [1, 2, 3, 4, 5, 6];
+    let image = ppm(2, 1, &rgb);
+    assert_eq!(&image[..], b"P6\n2 1\n255\n\x01\x02\x03\x04\x05\x06");
+}
+
+#[test]
+fn iterm2_escape_wraps_base64() {
+    let escape = iterm2_escape(b"hi");
+    assert_eq!(escape, "\x1b]1337;File=inline=1;size=2:aGk=\x07");
+}
+
+#[test]
+fn kitty_escape_single_chunk() {
+    let escape = kitty_escape(1, 1, &[255, 0, 0]);
+    assert_eq!(escape, "\x1b_Ga=T,f=24,s=1,v=1,m=0;/wAA\x1b\\");
+}
+
+#[test]
+fn kitty_escape_chunks_at_4096_bytes() {
+    let rgb = vec![0u8; 4000 * 3];
+    let escape = kitty_escape(4000, 1, &rgb);
+    let chunk_count = escape.matches("\x1b_G").count();
+    assert_eq!(chunk_count, 4);
+}
@@ -1,43 +1,32 @@
+#[cfg(feature = "bignum")]
+pub mod bignum;
 pub mod complex;
 use complex::*;
+pub mod image;
+pub mod mandelbrot;
+use mandelbrot::*;
+pub mod palette;
+use palette::UnicodeRampPalette;
 pub mod view;
 use view::*;
 
-#[inline(always)]
-fn has_exploded(p: C32) -> bool {
-    p.distance(complex::ORIGIN) > 2.0
-}
-
 fn main() {
-    let mut view = View::new(80, 40);
+    let width = 80;
+    let height = 40;
+    let max_iter = 256;
+
+    let viewport = Viewport::new(C64::from((-0.5, 0.0)), 1.0 / 20.0, width, height);
+    let mut view: View<Option<f64>> = View::new(width, height);
 
     for x in 0..view.width {
         for y in 0..view.height {
-            let mut fill = false;
-            let p: C32 = (x as f32 / 30.0, y as f32 / 15.0).into();
-
-            /*
-            if has_exploded(p) {
-                view.set(x, y, true);
-            }
-            */
-
-            /* // this works correctly
-            if x % 2 == 1 {
-                fill = true;
-            }
-            */
-
-            /* // this highlights a mistake i need to fix in the Display code
-            if y % 2 == 1 {
-                fill = true;
-            }
-            */
-
-            view.set(x, y, fill);
-            // mandelbrot check
+            let c = viewport.point(x, y);
+            view.set(x, y, escape(c, max_iter));
         }
     }
 
-    println!("{view}");
+    let palette = UnicodeRampPalette {
+        max_iter: max_iter as f64,
+    };
+    print!("{}", view.render(&palette));
 }
@@ -0,0 +1,126 @@
+use crate::complex::C64;
+
+/// A numeric backend the escape-time iteration can run on.
+///
+/// Implemented for [`C64`], and for
+/// [`FixedComplex`](crate::bignum::FixedComplex) (behind the `bignum`
+/// feature) once `f64` runs out of precision on a deep zoom.
+pub trait Escapable: Clone + core::ops::Add<Output = Self> + core::ops::Mul<Output = Self> {
+    /// The starting value `0`, matching `self`'s representation (e.g.
+    /// its fixed-point precision).
+    fn zero_like(&self) -> Self;
+
+    /// Whether this value has left the radius-2 escape circle.
+    fn has_escaped(&self) -> bool;
+
+    /// An approximate magnitude, used only for the fractional coloring
+    /// term — precision lost here doesn't affect the escape trajectory
+    /// itself.
+    fn approx_abs(&self) -> f64;
+}
+
+impl Escapable for C64 {
+    fn zero_like(&self) -> Self {
+        (0.0, 0.0).into()
+    }
+
+    fn has_escaped(&self) -> bool {
+        self.norm_sqr() > 4.0
+    }
+
+    fn approx_abs(&self) -> f64 {
+        self.norm_sqr().sqrt()
+    }
+}
+
+/// Escape-time test for a point in the complex plane.
+///
+/// Starting from `z = 0`, iterates `z = z² + c` up to `max_iter` times.
+/// Bails out as soon as `z` escapes the radius-2 circle and returns a
+/// smoothed, fractional iteration count. If the point survives every
+/// iteration it's presumed to be in the Mandelbrot set and `None` is
+/// returned.
+///
+/// Generic over the numeric backend `C` (see [`Escapable`]), so callers
+/// can swap in [`FixedComplex`](crate::bignum::FixedComplex) once `C64`
+/// runs out of precision on a deep zoom.
+pub fn escape<C: Escapable>(c: C, max_iter: u32) -> Option<f64> {
+    let mut z = c.zero_like();
+
+    for n in 0..max_iter {
+        z = z.clone() * z + c.clone();
+
+        if z.has_escaped() {
+            // Smooth/continuous coloring, see
+            // https://linas.org/art-gallery/escape/escape.html
+            let nu = f64::from(n) + 1.0 - z.approx_abs().ln().ln() / 2.0f64.ln();
+            return Some(nu);
+        }
+    }
+
+    None
+}
+
+/// Maps pixel coordinates onto the complex plane, so callers can pan and
+/// zoom the rendered fractal.
+pub struct Viewport {
+    pub center: C64,
+    pub scale: f64,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Viewport {
+    pub fn new(center: C64, scale: f64, width: u16, height: u16) -> Self {
+        Self {
+            center,
+            scale,
+            width,
+            height,
+        }
+    }
+
+    /// Maps a pixel coordinate `(px, py)` to the complex number it
+    /// represents: `center + ((px − w/2) + (py − h/2)·i) · scale`.
+    pub fn point(&self, px: u16, py: u16) -> C64 {
+        let dx = px as f64 - self.width as f64 / 2.0;
+        let dy = py as f64 - self.height as f64 / 2.0;
+        let offset: C64 = (dx, dy).into();
+        let scale: C64 = (self.scale, 0.0).into();
+
+        self.center + offset * scale
+    }
+
+    /// Bits of fractional precision a deep zoom at this viewport's scale
+    /// needs to stay crisp, for use with
+    /// [`FixedComplex`](crate::bignum::FixedComplex) once `scale` drops
+    /// below what `f64` can resolve (~2⁻⁵²).
+    pub fn precision_bits(&self) -> u32 {
+        let needed = (-self.scale.log2()).ceil().max(0.0) as u32;
+        (needed + 16).max(64)
+    }
+}
+
+#[test]
+fn escape_point_in_set() {
+    let c: C64 = (0.0, 0.0).into();
+    assert_eq!(escape(c, 50), None);
+}
+
+#[test]
+fn escape_point_outside_set() {
+    let c: C64 = (2.0, 2.0).into();
+    assert!(escape(c, 50).is_some());
+}
+
+#[test]
+fn viewport_point_center() {
+    let viewport = Viewport::new(C64::from((1.0, 2.0)), 0.1, 80, 40);
+    assert_eq!(viewport.point(40, 20), (1.0, 2.0).into());
+}
+
+#[test]
+fn viewport_point_offset() {
+    let viewport = Viewport::new(C64::from((0.0, 0.0)), 0.5, 80, 40);
+    assert_eq!(viewport.point(41, 21), (0.5, 0.5).into());
+}
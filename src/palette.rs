@@ -0,0 +1,182 @@
+//! Maps a [`View`](crate::view::View)'s per-cell values to what gets
+//! printed for them.
+
+/// Renders a single cell's value to the string printed for it (which may
+/// include escape sequences, but never a trailing newline — that's
+/// handled by the [`View`](crate::view::View)).
+pub trait Palette<T> {
+    fn render(&self, value: &T) -> String;
+
+    /// Appended once at the end of every row, after the last cell's
+    /// output but before the newline. Palettes that leave terminal state
+    /// dirty (e.g. an open truecolor escape) should reset it here.
+    fn line_end(&self) -> &str {
+        ""
+    }
+}
+
+/// Palettes that can also supply an actual pixel color, for raster
+/// image export (see
+/// [`View::to_inline_image`](crate::view::View::to_inline_image)) in
+/// addition to terminal glyphs.
+pub trait ColorPalette<T>: Palette<T> {
+    fn color(&self, value: &T) -> (u8, u8, u8);
+}
+
+/// The original on/off block-character rendering.
+pub struct BoolPalette {
+    pub off: char,
+    pub on: char,
+}
+
+impl Default for BoolPalette {
+    fn default() -> Self {
+        Self {
+            off: '░',
+            on: '█',
+        }
+    }
+}
+
+impl Palette<bool> for BoolPalette {
+    fn render(&self, value: &bool) -> String {
+        (if *value { self.on } else { self.off }).to_string()
+    }
+}
+
+impl ColorPalette<bool> for BoolPalette {
+    fn color(&self, value: &bool) -> (u8, u8, u8) {
+        if *value { (255, 255, 255) } else { (0, 0, 0) }
+    }
+}
+
+/// Unicode shading ramp, from empty to fully filled.
+const RAMP: [char; 5] = [' ', '░', '▒', '▓', '█'];
+
+/// Quantizes a smooth Mandelbrot iteration count onto [`RAMP`].
+///
+/// `None` (the point never escaped, i.e. is in the set) renders as the
+/// densest glyph.
+pub struct UnicodeRampPalette {
+    pub max_iter: f64,
+}
+
+impl Palette<Option<f64>> for UnicodeRampPalette {
+    fn render(&self, value: &Option<f64>) -> String {
+        let normalized = normalize(*value, self.max_iter);
+        let idx = (normalized * (RAMP.len() - 1) as f64).round() as usize;
+
+        RAMP[idx.min(RAMP.len() - 1)].to_string()
+    }
+}
+
+/// 24-bit ANSI truecolor rendering of a smooth Mandelbrot iteration
+/// count, emitting `\x1b[38;2;{r};{g};{b}m` before each glyph.
+pub struct TruecolorPalette {
+    pub max_iter: f64,
+    pub glyph: char,
+}
+
+impl TruecolorPalette {
+    pub fn new(max_iter: f64) -> Self {
+        Self {
+            max_iter,
+            glyph: '█',
+        }
+    }
+}
+
+impl Palette<Option<f64>> for TruecolorPalette {
+    fn render(&self, value: &Option<f64>) -> String {
+        let normalized = normalize(*value, self.max_iter);
+        let (r, g, b) = gradient(normalized);
+
+        format!("\x1b[38;2;{r};{g};{b}m{}", self.glyph)
+    }
+
+    fn line_end(&self) -> &str {
+        "\x1b[0m"
+    }
+}
+
+impl ColorPalette<Option<f64>> for TruecolorPalette {
+    fn color(&self, value: &Option<f64>) -> (u8, u8, u8) {
+        gradient(normalize(*value, self.max_iter))
+    }
+}
+
+/// Normalizes a smooth escape count to `[0, 1]`, treating points in the
+/// set (`None`) as fully saturated.
+fn normalize(value: Option<f64>, max_iter: f64) -> f64 {
+    match value {
+        None => 1.0,
+        Some(nu) => (nu / max_iter).clamp(0.0, 1.0),
+    }
+}
+
+/// A small gradient whose hue runs from blue through green and yellow to
+/// red while its brightness fades to black, chosen to make escape bands
+/// easy to tell apart near the boundary of the set.
+fn gradient(t: f64) -> (u8, u8, u8) {
+    let hue = 0.66 - t * 0.66; // blue (0.66) down to red (0.0)
+    hsv_to_rgb(hue, 0.8, (1.0 - t).sqrt())
+}
+
+fn hsv_to_rgb(h: f64, s: f64, v: f64) -> (u8, u8, u8) {
+    let i = (h * 6.0).floor();
+    let f = h * 6.0 - i;
+    let p = v * (1.0 - s);
+    let q = v * (1.0 - f * s);
+    let t = v * (1.0 - (1.0 - f) * s);
+
+    let (r, g, b) = match i as i64 % 6 {
+        0 => (v, t, p),
+        1 => (q, v, p),
+        2 => (p, v, t),
+        3 => (p, q, v),
+        4 => (t, p, v),
+        _ => (v, p, q),
+    };
+
+    (
+        (r * 255.0).round() as u8,
+        (g * 255.0).round() as u8,
+        (b * 255.0).round() as u8,
+    )
+}
+
+#[test]
+fn bool_palette_render() {
+    let palette = BoolPalette::default();
+    assert_eq!(palette.render(&true), "█");
+    assert_eq!(palette.render(&false), "░");
+}
+
+#[test]
+fn unicode_ramp_palette_quantizes() {
+    let palette = UnicodeRampPalette { max_iter: 100.0 };
+    assert_eq!(palette.render(&Some(0.0)), " ");
+    assert_eq!(palette.render(&Some(100.0)), "█");
+    assert_eq!(palette.render(&None), "█");
+}
+
+#[test]
+fn truecolor_palette_emits_escape_and_resets() {
+    let palette = TruecolorPalette::new(100.0);
+    let rendered = palette.render(&Some(50.0));
+    assert!(rendered.starts_with("\x1b[38;2;"));
+    assert!(rendered.ends_with('█'));
+    assert_eq!(palette.line_end(), "\x1b[0m");
+}
+
+#[test]
+fn gradient_extremes() {
+    assert_eq!(gradient(0.0), hsv_to_rgb(0.66, 0.8, 1.0));
+    assert_eq!(gradient(1.0), hsv_to_rgb(0.0, 0.8, 0.0));
+}
+
+#[test]
+fn hsv_to_rgb_primaries() {
+    assert_eq!(hsv_to_rgb(0.0, 1.0, 1.0), (255, 0, 0));
+    assert_eq!(hsv_to_rgb(0.5, 1.0, 1.0), (0, 255, 255));
+}
@@ -1,54 +1,50 @@
 use std::fmt::{self, Debug, Display};
 
-/// A 2D grid for simple terminal graphics
-#[derive(PartialEq, Eq, Clone, Debug)]
-pub struct View {
+use crate::palette::{BoolPalette, Palette};
+
+/// A 2D grid for simple terminal graphics.
+///
+/// Generic over the per-cell value `T`, defaulting to `bool` to preserve
+/// the original on/off grid. Richer values (e.g. smooth Mandelbrot
+/// iteration counts) can be stored directly and turned into output via
+/// [`render_with`](View::render_with) or a [`Palette`].
+#[derive(PartialEq, Clone, Debug)]
+pub struct View<T = bool> {
     pub width: u16,
     pub height: u16,
-    pub symbol_off: char,
-    pub symbol_on: char,
     // @TODO: optimize to use a Vec<usize> with tons of bit-sorcery
-    buffer: Vec<bool>,
+    buffer: Vec<T>,
 }
 
-impl View {
+impl<T: Default + Clone> View<T> {
     pub fn new(width: u16, height: u16) -> Self {
         let buf_size = (width * height) as usize;
-        let buffer = std::iter::repeat(false).take(buf_size).collect();
+        let buffer = vec![T::default(); buf_size];
 
         Self {
             width,
             height,
             buffer,
-            symbol_off: '░',
-            symbol_on: '█',
         }
     }
+}
 
-    /// Set the characters to print for a coordinate's value.
-    ///
-    /// Defaults are `off` = `░`, `on` = `█`
-    pub fn set_symbols(mut self, off: char, on: char) -> Self {
-        self.symbol_off = off;
-        self.symbol_on = on;
-        self
-    }
-
+impl<T> View<T> {
     /// Dimensions as a (width, height) tuple
     pub fn dimensions(&self) -> (u16, u16) {
         (self.width, self.height)
     }
 }
 
-impl View {
+impl<T> View<T> {
     #[inline(always)]
     fn index(&self, x: u16, y: u16) -> usize {
-        (y * self.height + x) as usize
+        (y * self.width + x) as usize
     }
 
     #[inline(always)]
     fn bounds_check(&self, x: u16, y: u16) -> bool {
-        return x < self.width && y < self.height;
+        x < self.width && y < self.height
     }
 
     fn checked_index(&self, x: u16, y: u16) -> Option<usize> {
@@ -56,18 +52,17 @@ impl View {
     }
 }
 
-impl View {
+impl<T: Copy> View<T> {
     /// Returns value at given coordinate while checking if valid.
-    pub fn at(&self, x: u16, y: u16) -> Option<bool> {
-        self.checked_index(x, y)
-            .map(|idx| self.buffer[idx as usize])
+    pub fn at(&self, x: u16, y: u16) -> Option<T> {
+        self.checked_index(x, y).map(|idx| self.buffer[idx])
     }
 
     /// Returns value at given coordinate panicking if outside view.
     ///
     /// Panics if coordinate is outside View
     #[inline(always)]
-    pub fn unchecked_at(&self, x: u16, y: u16) -> bool {
+    pub fn unchecked_at(&self, x: u16, y: u16) -> T {
         self.at(x, y).expect("Given coordinate not inside View")
     }
 
@@ -77,7 +72,7 @@ impl View {
     /// Set the value at a coordinate
     ///
     /// Returns whether it was successful or not, i.e. the coordinate was within bounds
-    pub fn set(&mut self, x: u16, y: u16, value: bool) -> bool {
+    pub fn set(&mut self, x: u16, y: u16, value: T) -> bool {
         if let Some(idx) = self.checked_index(x, y) {
             self.buffer[idx] = value;
             true
@@ -89,42 +84,64 @@ impl View {
     /// Iterate over View with coordinates
     ///
     /// Item resembles `(x, y, value)`
-    pub fn iter<'v>(&'v self) -> ViewIter<'v> {
+    pub fn iter<'v>(&'v self) -> ViewIter<'v, T> {
         ViewIter {
             view: self,
             x: 0,
             y: 0,
         }
     }
-}
 
-impl Display for View {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// Renders every cell through `f`, one row per line.
+    pub fn render_with<F: Fn(T) -> String>(&self, f: F) -> String {
         let buf_size = self.height * (self.width + 1); // +1 to include newlines
         let mut buf = String::with_capacity(buf_size as usize);
 
         self.iter().for_each(|(x, _, val)| {
-            buf.push(if val { self.symbol_on } else { self.symbol_off });
+            buf.push_str(&f(val));
+
+            if x == self.width - 1 {
+                buf.push('\n');
+            }
+        });
+
+        buf
+    }
+
+    /// Renders every cell through a [`Palette`], one row per line.
+    pub fn render(&self, palette: &impl Palette<T>) -> String {
+        let buf_size = self.height * (self.width + 1);
+        let mut buf = String::with_capacity(buf_size as usize);
+
+        self.iter().for_each(|(x, _, val)| {
+            buf.push_str(&palette.render(&val));
 
             if x == self.width - 1 {
+                buf.push_str(palette.line_end());
                 buf.push('\n');
             }
         });
 
-        write!(f, "{buf}")
+        buf
+    }
+}
+
+impl Display for View<bool> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(&BoolPalette::default()))
     }
 }
 
 /// Iterate over a complete View
-pub struct ViewIter<'v> {
-    view: &'v View,
+pub struct ViewIter<'v, T> {
+    view: &'v View<T>,
     x: u16,
     y: u16,
 }
 
-impl Iterator for ViewIter<'_> {
+impl<'v, T: Copy> Iterator for ViewIter<'v, T> {
     // (x, y, val)
-    type Item = (u16, u16, bool);
+    type Item = (u16, u16, T);
 
     fn next(&mut self) -> Option<Self::Item> {
         let val = self
@@ -143,17 +160,44 @@ impl Iterator for ViewIter<'_> {
     }
 }
 
-// @TODO: write tests
 #[test]
 fn at() {
-    unimplemented!()
+    let mut view: View<u8> = View::new(2, 2);
+    view.set(1, 0, 7);
+
+    assert_eq!(view.at(1, 0), Some(7));
+    assert_eq!(view.at(0, 0), Some(0));
+    assert_eq!(view.at(2, 0), None);
+    assert_eq!(view.at(0, 2), None);
 }
 
 #[test]
 fn unchecked_at() {
-    unimplemented!()
+    let mut view: View<u8> = View::new(2, 2);
+    view.set(1, 0, 7);
+
+    assert_eq!(view.unchecked_at(1, 0), 7);
 }
 
 #[test]
 #[should_panic]
-fn unchecked_at_panic() {}
+fn unchecked_at_panic() {
+    let view: View<u8> = View::new(2, 2);
+    view.unchecked_at(2, 0);
+}
+
+#[test]
+fn render_with() {
+    let mut view: View<u8> = View::new(2, 1);
+    view.set(0, 0, 1);
+    view.set(1, 0, 2);
+    assert_eq!(view.render_with(|v| v.to_string()), "12\n");
+}
+
+#[test]
+fn render_with_palette() {
+    let mut view: View<bool> = View::new(2, 1);
+    view.set(1, 0, true);
+    let rendered = view.render(&BoolPalette::default());
+    assert_eq!(rendered, "░█\n");
+}